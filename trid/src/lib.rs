@@ -37,6 +37,9 @@ use core::{
 
 pub const LENGTH: usize = 11;
 
+/// Length of a Turkish Tax Identification Number (Vergi Kimlik Numarası).
+pub const TAX_ID_LENGTH: usize = 10;
+
 /// Turkish citizenship ID number. The number is stored as ASCII digits
 /// "0".."9" in the structure.
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
@@ -50,19 +53,52 @@ pub enum Error {
     /// The length of the ID isn't {LENGTH}
     InvalidLength,
 
-    /// There's an invalid character in the ID string
-    InvalidCharacter(char),
+    /// There's an invalid character at the given zero-based index in the
+    /// ID string
+    InvalidCharacter {
+        /// The offending character
+        ch: char,
+        /// Zero-based index of `ch` within the input
+        index: usize,
+    },
 
-    /// The final checksum mismatches
-    InvalidFinalChecksum,
+    /// The final checksum mismatches, at the given zero-based index
+    InvalidFinalChecksum {
+        /// Zero-based index of the final checksum digit
+        index: usize,
+    },
 
-    /// The initial checksum mismatches
-    InvalidInitialChecksum,
+    /// The initial checksum mismatches, at the given zero-based index
+    InvalidInitialChecksum {
+        /// Zero-based index of the initial checksum digit
+        index: usize,
+    },
 
     /// ID's first digit is zero
     FirstDigitIsZero,
 }
 
+/// Human-readable descriptions of validation failures.
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        match self {
+            Error::InvalidLength => write!(f, "invalid length: expected {LENGTH} digits"),
+            Error::InvalidCharacter { ch, index } => {
+                write!(f, "invalid character '{ch}' at position {index}")
+            }
+            Error::InvalidFinalChecksum { index } => {
+                write!(f, "invalid final checksum at position {index}")
+            }
+            Error::InvalidInitialChecksum { index } => {
+                write!(f, "invalid initial checksum at position {index}")
+            }
+            Error::FirstDigitIsZero => write!(f, "first digit is zero"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
 /// Checks if the given string slice is a valid Turkish citizenship ID number.
 ///
 /// # Arguments
@@ -87,59 +123,7 @@ pub enum Error {
 /// ```
 #[must_use = "validity check must not be ignored"]
 pub fn is_valid(value: &str) -> bool {
-    validate(value).is_ok()
-}
-
-/// Internal function to validate a given Turkish ID number.
-fn validate(str: &str) -> Result<(), Error> {
-    /// Iterates over a char iterator and returns an i32 representing
-    /// the next digit, or returns an error if the digit can't be parsed.
-    fn next_digit(t: &mut impl Iterator<Item = char>) -> Result<i32, Error> {
-        let c = t.next().ok_or(Error::InvalidLength)?;
-
-        // convert digit to u32 value
-        c.to_digit(10)
-            .and_then(|d| i32::try_from(d).ok()) // u32 -> i32
-            .ok_or(Error::InvalidCharacter(c))
-    }
-
-    if str.len() != LENGTH {
-        return Err(Error::InvalidLength);
-    }
-
-    let mut digits = str.chars();
-
-    // start calculating checksums
-    let mut odd_sum = next_digit(&mut digits)?;
-    if odd_sum == 0 {
-        // the first digit cannot be zero
-        return Err(Error::FirstDigitIsZero);
-    }
-
-    let mut even_sum = 0;
-    for _ in 0..4 {
-        even_sum += next_digit(&mut digits)?;
-        odd_sum += next_digit(&mut digits)?;
-    }
-
-    let first_checksum = next_digit(&mut digits)?;
-    let final_checksum = next_digit(&mut digits)?;
-
-    // we check for the final checksum first because it's computationally
-    // cheaper.
-    let final_checksum_computed = (odd_sum + even_sum + first_checksum) % 10;
-    if final_checksum_computed != final_checksum {
-        return Err(Error::InvalidFinalChecksum);
-    }
-
-    // we use euclidian remainder due to the possibility that the final
-    // checksum wmight be negative.
-    let first_checksum_computed = ((odd_sum * 7) - even_sum).rem_euclid(10);
-    if first_checksum_computed != first_checksum {
-        return Err(Error::InvalidInitialChecksum);
-    }
-
-    Ok(())
+    TurkishId::is_valid_const(value)
 }
 
 /// TurkishId types are displayed as regular numbers.
@@ -199,6 +183,137 @@ impl TurkishId {
         d[10] = to_ascii(second_checksum);
         Ok(TurkishId { id: d })
     }
+
+    /// Returns the 9-digit sequence number this `TurkishId` was derived
+    /// from, i.e. the inverse of [`TurkishId::from_seq`]. The last two
+    /// digits of the ID are checksums fully determined by these nine, so
+    /// they carry no extra information.
+    pub fn to_seq(&self) -> u32 {
+        // the first 9 bytes are always ASCII digits, guaranteed by
+        // `validate_const` and `from_seq`
+        str::from_utf8(&self.id[..9])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .expect("TurkishId must hold 9 leading ASCII digits")
+    }
+
+    /// Packs this `TurkishId` into a compact 4-byte big-endian encoding of
+    /// its sequence number, for use as a database key or wire format.
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.to_seq().to_be_bytes()
+    }
+
+    /// Unpacks a `TurkishId` from the 4-byte encoding produced by
+    /// [`TurkishId::to_bytes`], re-deriving its checksums from the decoded
+    /// sequence number. A blob whose sequence number is outside
+    /// [`TurkishId::SEQ_RANGE`] is rejected; a corrupted blob that still
+    /// decodes to an in-range sequence number yields a different, valid
+    /// `TurkishId` and is not detected.
+    pub fn from_bytes(bytes: &[u8; 4]) -> Result<TurkishId, FromSeqError> {
+        Self::from_seq(u32::from_be_bytes(*bytes))
+    }
+
+    /// Validates a Turkish citizenship ID number in a `const` context.
+    ///
+    /// This is the checksum core that [`is_valid`] and [`FromStr::from_str`]
+    /// delegate to; it's implemented over raw bytes without iterators or
+    /// trait methods that aren't const-stable, so it can run at compile
+    /// time to build `static` lookup tables or validate literals.
+    ///
+    /// Because it works over bytes rather than `char`s, a non-ASCII byte is
+    /// reported with its raw byte value reinterpreted as a `char` rather
+    /// than the Unicode scalar it's actually part of; this only affects the
+    /// diagnostic payload of already-invalid input.
+    pub const fn validate_const(s: &str) -> Result<(), Error> {
+        const fn digit(bytes: &[u8], index: usize) -> Result<i32, Error> {
+            let b = bytes[index];
+            if b.is_ascii_digit() {
+                Ok((b - b'0') as i32)
+            } else {
+                Err(Error::InvalidCharacter {
+                    ch: b as char,
+                    index,
+                })
+            }
+        }
+
+        let bytes = s.as_bytes();
+        if bytes.len() != LENGTH {
+            return Err(Error::InvalidLength);
+        }
+
+        let first = match digit(bytes, 0) {
+            Ok(d) => d,
+            Err(e) => return Err(e),
+        };
+        if first == 0 {
+            return Err(Error::FirstDigitIsZero);
+        }
+
+        let mut odd_sum = first;
+        let mut even_sum = 0;
+        let mut i = 1;
+        while i < 9 {
+            even_sum += match digit(bytes, i) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            };
+            i += 1;
+            odd_sum += match digit(bytes, i) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            };
+            i += 1;
+        }
+
+        let first_checksum = match digit(bytes, 9) {
+            Ok(d) => d,
+            Err(e) => return Err(e),
+        };
+        let final_checksum = match digit(bytes, 10) {
+            Ok(d) => d,
+            Err(e) => return Err(e),
+        };
+
+        // we check for the final checksum first because it's computationally
+        // cheaper.
+        let final_checksum_computed = (odd_sum + even_sum + first_checksum) % 10;
+        if final_checksum_computed != final_checksum {
+            return Err(Error::InvalidFinalChecksum { index: 10 });
+        }
+
+        // we use euclidian remainder due to the possibility that the final
+        // checksum might be negative.
+        let first_checksum_computed = ((odd_sum * 7) - even_sum).rem_euclid(10);
+        if first_checksum_computed != first_checksum {
+            return Err(Error::InvalidInitialChecksum { index: 9 });
+        }
+
+        Ok(())
+    }
+
+    /// Checks, in a `const` context, whether `s` is a valid Turkish
+    /// citizenship ID number. See [`TurkishId::validate_const`].
+    pub const fn is_valid_const(s: &str) -> bool {
+        matches!(Self::validate_const(s), Ok(()))
+    }
+
+    /// Parses `s` into a `TurkishId` in a `const` context, so compile-time
+    /// checked ID literals and `static` lookup tables can be built with
+    /// zero runtime cost. See [`TurkishId::validate_const`].
+    pub const fn from_str_const(s: &str) -> Result<TurkishId, Error> {
+        if let Err(e) = Self::validate_const(s) {
+            return Err(e);
+        }
+        let bytes = s.as_bytes();
+        let mut id = [0u8; LENGTH];
+        let mut i = 0;
+        while i < LENGTH {
+            id[i] = bytes[i];
+            i += 1;
+        }
+        Ok(TurkishId { id })
+    }
 }
 
 /// TurkishId can only be constructed from a string despite that it's stored
@@ -206,12 +321,345 @@ impl TurkishId {
 impl FromStr for TurkishId {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate(s)?;
-        let bytes = s.as_bytes().try_into().map_err(|_| Error::InvalidLength)?;
+        TurkishId::from_str_const(s)
+    }
+}
+
+/// Controls how [`TurkishId::scan`] matches against runs of digits that are
+/// longer than [`LENGTH`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ScanMode {
+    /// A match is accepted anywhere inside a longer run of digits, e.g. the
+    /// last 11 digits of a 12-digit run are still considered a candidate.
+    Embedded,
+
+    /// A match must be a maximal run of digits: it has to be bounded by a
+    /// non-digit character (or the start/end of the text) on both sides.
+    Bounded,
+}
+
+/// Finds the next length-[`LENGTH`] window worth testing against the
+/// checksum rules, honoring `mode`, and returns its byte range. `pos` is
+/// advanced past the window's digit run in [`ScanMode::Bounded`], and only
+/// one byte past the window's start in [`ScanMode::Embedded`], so that
+/// overlapping windows are still visited. Returns `None` once `text` is
+/// exhausted. Shared by [`Scan`] and
+/// [`ScanOffsets`] so both agree on exactly which windows are candidates.
+///
+/// `run_end` caches the end of the digit run `pos` currently falls
+/// inside, so that sliding the window forward in [`ScanMode::Embedded`]
+/// doesn't have to rescan the same run byte-by-byte on every step; `None`
+/// means `pos` isn't (yet known to be) inside a run.
+fn next_candidate_range(
+    text: &str,
+    mode: ScanMode,
+    pos: &mut usize,
+    run_end: &mut Option<usize>,
+) -> Option<Range<usize>> {
+    let bytes = text.as_bytes();
+    loop {
+        let end = match *run_end {
+            Some(end) => end,
+            None => {
+                while *pos < bytes.len() && !bytes[*pos].is_ascii_digit() {
+                    *pos += 1;
+                }
+                if *pos >= bytes.len() {
+                    return None;
+                }
+
+                let mut end = *pos;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+                *run_end = Some(end);
+                end
+            }
+        };
+
+        let start = *pos;
+        let run_len = end - start;
+
+        if mode == ScanMode::Bounded {
+            *pos = end;
+            *run_end = None;
+            if run_len == LENGTH {
+                return Some(start..end);
+            }
+            continue;
+        }
+
+        if run_len < LENGTH {
+            *pos = end;
+            *run_end = None;
+            continue;
+        }
+
+        *pos = start + 1;
+        return Some(start..start + LENGTH);
+    }
+}
+
+/// Iterator over every valid [`TurkishId`] embedded in a piece of text,
+/// returned by [`TurkishId::scan`].
+///
+/// Yields `(offset, id)` pairs where `offset` is the byte offset of the
+/// match within the original text.
+pub struct Scan<'a> {
+    text: &'a str,
+    mode: ScanMode,
+    pos: usize,
+
+    /// End of the digit run `pos` currently falls inside, cached so that
+    /// sliding the window forward in [`ScanMode::Embedded`] doesn't have to
+    /// rescan the same run byte-by-byte on every step. `None` means `pos`
+    /// isn't (yet known to be) inside a run.
+    run_end: Option<usize>,
+}
+
+impl<'a> Iterator for Scan<'a> {
+    type Item = (usize, TurkishId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(range) =
+            next_candidate_range(self.text, self.mode, &mut self.pos, &mut self.run_end)
+        {
+            if let Ok(id) = self.text[range.clone()].parse() {
+                return Some((range.start, id));
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the byte offsets of every valid [`TurkishId`] embedded in
+/// a piece of text, returned by [`TurkishId::scan_offsets`].
+///
+/// Reports the same offsets as [`Scan`] without constructing a `TurkishId`
+/// for each match.
+pub struct ScanOffsets<'a> {
+    text: &'a str,
+    mode: ScanMode,
+    pos: usize,
+    run_end: Option<usize>,
+}
+
+impl<'a> Iterator for ScanOffsets<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(range) =
+            next_candidate_range(self.text, self.mode, &mut self.pos, &mut self.run_end)
+        {
+            if TurkishId::is_valid_const(&self.text[range.clone()]) {
+                return Some(range.start);
+            }
+        }
+        None
+    }
+}
+
+impl TurkishId {
+    /// Scans arbitrary text for every valid Turkish citizenship ID number it
+    /// contains, without allocating.
+    ///
+    /// Runs of ASCII digits are located first, then every length-[`LENGTH`]
+    /// window inside each run is checked against the same checksum rules
+    /// used by [`FromStr`]. `mode` controls whether a match may be embedded
+    /// inside a longer digit run or must be exactly bounded by non-digit
+    /// characters on both sides.
+    ///
+    /// # Example
+    /// ```
+    /// use trid::{TurkishId, ScanMode};
+    ///
+    /// let text = "name: John, id: 76558242278, done";
+    /// let found: Vec<_> = TurkishId::scan(text, ScanMode::Bounded).collect();
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].0, 16);
+    /// ```
+    pub fn scan(text: &str, mode: ScanMode) -> Scan<'_> {
+        Scan {
+            text,
+            mode,
+            pos: 0,
+            run_end: None,
+        }
+    }
+
+    /// Lighter-weight counterpart to [`TurkishId::scan`] that only reports
+    /// match offsets, without constructing a `TurkishId` for each one --
+    /// the same relationship [`is_valid`] has to [`FromStr::from_str`],
+    /// applied to scanning. Useful for callers, e.g. redaction pipelines,
+    /// that only need to know *where* a match is.
+    ///
+    /// # Example
+    /// ```
+    /// use trid::{TurkishId, ScanMode};
+    ///
+    /// let text = "name: John, id: 76558242278, done";
+    /// let offsets: Vec<_> = TurkishId::scan_offsets(text, ScanMode::Bounded).collect();
+    /// assert_eq!(offsets, vec![16]);
+    /// ```
+    pub fn scan_offsets(text: &str, mode: ScanMode) -> ScanOffsets<'_> {
+        ScanOffsets {
+            text,
+            mode,
+            pos: 0,
+            run_end: None,
+        }
+    }
+}
+
+/// Turkish Tax Identification Number (Vergi Kimlik Numarası). The number is
+/// stored as ASCII digits "0".."9" in the structure.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct TaxId {
+    id: [u8; TAX_ID_LENGTH],
+}
+
+/// Represents the parser error for a given Turkish Tax Identification Number.
+#[derive(Debug, Eq, PartialEq)]
+pub enum TaxIdError {
+    /// The length of the ID isn't {TAX_ID_LENGTH}
+    InvalidLength,
+
+    /// There's an invalid character in the ID string
+    InvalidCharacter(char),
+
+    /// The checksum mismatches
+    InvalidChecksum,
+}
+
+/// Checks if the given string slice is a valid Turkish Tax Identification
+/// Number.
+///
+/// # Arguments
+///
+/// * `value` - The string to check.
+///
+/// # Returns
+///
+/// `true` if the string is a valid Turkish Tax Identification Number,
+/// `false` otherwise.
+///
+/// # Example
+/// ```
+/// use trid;
+///
+/// assert!(trid::is_valid_tax_id("1234567890"));
+/// ```
+#[must_use = "validity check must not be ignored"]
+pub fn is_valid_tax_id(value: &str) -> bool {
+    validate_tax_id(value).is_ok()
+}
+
+/// Internal function to validate a given Turkish Tax Identification Number.
+fn validate_tax_id(str: &str) -> Result<(), TaxIdError> {
+    fn next_digit(t: &mut impl Iterator<Item = char>) -> Result<i32, TaxIdError> {
+        let c = t.next().ok_or(TaxIdError::InvalidLength)?;
+        c.to_digit(10)
+            .and_then(|d| i32::try_from(d).ok())
+            .ok_or(TaxIdError::InvalidCharacter(c))
+    }
+
+    if str.len() != TAX_ID_LENGTH {
+        return Err(TaxIdError::InvalidLength);
+    }
+
+    let mut digits = str.chars();
+    let mut first_nine = [0i32; 9];
+    for item in &mut first_nine {
+        *item = next_digit(&mut digits)?;
+    }
+    let check_digit = next_digit(&mut digits)?;
+
+    let mut sum = 0;
+    for (i, &digit) in first_nine.iter().enumerate() {
+        let mut tmp = (digit + (9 - i as i32)) % 10;
+        if tmp != 0 {
+            tmp = (tmp * (1 << (9 - i))) % 9;
+            if tmp == 0 {
+                tmp = 9;
+            }
+        }
+        sum += tmp;
+    }
+    let computed_check_digit = (10 - (sum % 10)) % 10;
+    if computed_check_digit != check_digit {
+        return Err(TaxIdError::InvalidChecksum);
+    }
+
+    Ok(())
+}
+
+/// TaxId types are displayed as regular numbers.
+impl Display for TaxId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            str::from_utf8(&self.id).map_err(|_| core::fmt::Error)?
+        )
+    }
+}
+
+/// TaxId can only be constructed from a string despite that it's stored
+/// as a fixed-length byte array internally.
+impl FromStr for TaxId {
+    type Err = TaxIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_tax_id(s)?;
+        let bytes = s.as_bytes().try_into().map_err(|_| TaxIdError::InvalidLength)?;
         let result = Self { id: bytes };
         Ok(result)
     }
 }
 
+/// Human-readable formats serialize/deserialize a `TurkishId` as its
+/// canonical 11-character string; binary formats use the compact sequence
+/// number from [`TurkishId::to_seq`], written through `serialize_u32` so
+/// both sides of the impl agree on a single wire shape (serializing as
+/// bytes but deserializing through the array/tuple `Deserialize` impl, as
+/// a first attempt did, round-trips on non-self-describing formats like
+/// `bincode` but panics on self-describing ones like CBOR or MessagePack,
+/// which tag bytes and tuples differently). Human-readable deserialization
+/// re-validates checksums, so an invalid ID fails with a descriptive error
+/// instead of silently constructing a bogus value; binary deserialization
+/// re-derives the checksums from the sequence number and fails only for a
+/// sequence number outside [`TurkishId::SEQ_RANGE`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for TurkishId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u32(self.to_seq())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TurkishId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        if deserializer.is_human_readable() {
+            let s = <&str>::deserialize(deserializer)?;
+            s.parse::<TurkishId>().map_err(D::Error::custom)
+        } else {
+            let seq = u32::deserialize(deserializer)?;
+            TurkishId::from_seq(seq)
+                .map_err(|_| D::Error::custom("sequence number out of range"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
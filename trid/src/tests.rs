@@ -19,23 +19,23 @@ const VALID_NUMBERS: &[&str] = &[
 
 const INVALID_NUMBERS: &[(&str, Error)] = &[
     ("04948892948", Error::FirstDigitIsZero), // first digit zero
-    ("14948892946", Error::InvalidFinalChecksum), // last checksum INVALID
-    ("14948892937", Error::InvalidInitialChecksum), // first checksum INVALID
+    ("14948892946", Error::InvalidFinalChecksum { index: 10 }), // last checksum INVALID
+    ("14948892937", Error::InvalidInitialChecksum { index: 9 }), // first checksum INVALID
     // non numeric chars
-    ("A4948892948", Error::InvalidCharacter('A')),
-    ("7B558242278", Error::InvalidCharacter('B')),
-    ("80C76431508", Error::InvalidCharacter('C')),
-    ("767D5508630", Error::InvalidCharacter('D')),
-    ("9079E350894", Error::InvalidCharacter('E')),
-    ("43473F24496", Error::InvalidCharacter('F')),
-    ("566733G2584", Error::InvalidCharacter('G')),
-    ("2926080H600", Error::InvalidCharacter('H')),
-    ("93212606I04", Error::InvalidCharacter('I')),
-    ("352014085J8", Error::InvalidCharacter('J')),
-    ("3520140853K", Error::InvalidCharacter('K')),
+    ("A4948892948", Error::InvalidCharacter { ch: 'A', index: 0 }),
+    ("7B558242278", Error::InvalidCharacter { ch: 'B', index: 1 }),
+    ("80C76431508", Error::InvalidCharacter { ch: 'C', index: 2 }),
+    ("767D5508630", Error::InvalidCharacter { ch: 'D', index: 3 }),
+    ("9079E350894", Error::InvalidCharacter { ch: 'E', index: 4 }),
+    ("43473F24496", Error::InvalidCharacter { ch: 'F', index: 5 }),
+    ("566733G2584", Error::InvalidCharacter { ch: 'G', index: 6 }),
+    ("2926080H600", Error::InvalidCharacter { ch: 'H', index: 7 }),
+    ("93212606I04", Error::InvalidCharacter { ch: 'I', index: 8 }),
+    ("352014085J8", Error::InvalidCharacter { ch: 'J', index: 9 }),
+    ("3520140853K", Error::InvalidCharacter { ch: 'K', index: 10 }),
     // spaces
-    (" 7655824227", Error::InvalidCharacter(' ')),
-    ("5582422781 ", Error::InvalidCharacter(' ')),
+    (" 7655824227", Error::InvalidCharacter { ch: ' ', index: 0 }),
+    ("5582422781 ", Error::InvalidCharacter { ch: ' ', index: 10 }),
     // uneven length
     ("", Error::InvalidLength),
     ("7", Error::InvalidLength),
@@ -72,6 +72,54 @@ fn parse_invalidnumbers_returns_correct_error() {
     }
 }
 
+#[test]
+fn error_display_is_human_readable() {
+    for (_, error) in INVALID_NUMBERS {
+        assert!(!error.to_string().is_empty());
+    }
+}
+
+#[test]
+fn is_valid_const_agrees_with_is_valid() {
+    for number in VALID_NUMBERS {
+        assert!(TurkishId::is_valid_const(number));
+    }
+    for (number, _) in INVALID_NUMBERS {
+        assert!(!TurkishId::is_valid_const(number));
+    }
+}
+
+#[test]
+fn from_str_const_agrees_with_from_str() {
+    for number in VALID_NUMBERS {
+        assert_eq!(
+            TurkishId::from_str_const(number),
+            TurkishId::from_str(number)
+        );
+    }
+    for (number, error) in INVALID_NUMBERS {
+        assert_eq!(TurkishId::from_str_const(number).err().as_ref(), Some(error));
+    }
+}
+
+// Embedding a checked ID literal in a `const` requires `from_str_const` (or
+// `is_valid_const`) to actually run at compile time.
+const COMPILE_TIME_CHECKED_ID: TurkishId = match TurkishId::from_str_const("76558242278") {
+    Ok(id) => id,
+    Err(_) => panic!("id literal failed const validation"),
+};
+
+#[test]
+fn from_str_const_can_build_compile_time_literals() {
+    assert_eq!(COMPILE_TIME_CHECKED_ID.to_string(), "76558242278");
+}
+
+#[test]
+fn from_str_const_rejects_bad_literal_at_compile_time() {
+    const BAD: Result<TurkishId, Error> = TurkishId::from_str_const("00000000000");
+    assert_eq!(BAD, Err(Error::FirstDigitIsZero));
+}
+
 #[test]
 fn hashset_compatible() {
     let mut set = HashSet::new();
@@ -108,3 +156,410 @@ fn from_seq_out_of_range_values_return_error() {
         assert_eq!(result.err(), Some(FromSeqError::OutOfRange));
     }
 }
+
+#[test]
+fn to_seq_roundtrips_with_from_seq() {
+    for number in VALID_NUMBERS {
+        let seq: u32 = number[..9].parse().unwrap();
+        let id = TurkishId::from_seq(seq).unwrap();
+        assert_eq!(seq, id.to_seq());
+    }
+}
+
+#[test]
+fn to_bytes_roundtrips_with_from_bytes() {
+    for number in VALID_NUMBERS {
+        let id = number.parse::<TurkishId>().unwrap();
+        let bytes = id.to_bytes();
+        let decoded = TurkishId::from_bytes(&bytes).unwrap();
+        assert_eq!(id, decoded);
+    }
+}
+
+#[test]
+fn from_bytes_rejects_out_of_range_sequences() {
+    for seq in OUT_OF_RANGE_SEQUENCES {
+        let result = TurkishId::from_bytes(&seq.to_be_bytes());
+        assert_eq!(result.err(), Some(FromSeqError::OutOfRange));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrips_through_human_readable_formats() {
+    for number in VALID_NUMBERS {
+        let id = number.parse::<TurkishId>().unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{number}\""));
+        let decoded: TurkishId = serde_json::from_str(&json).unwrap();
+        assert_eq!(id, decoded);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_deserialize_rejects_invalid_ids() {
+    for (number, _) in INVALID_NUMBERS {
+        let json = format!("\"{number}\"");
+        let result: Result<TurkishId, _> = serde_json::from_str(&json);
+        assert!(result.is_err());
+    }
+}
+
+/// A minimal, non-self-describing `Serializer`/`Deserializer` pair that
+/// only understands a single `u32`, the way formats like `bincode` do.
+/// `serde_json` is human-readable, so it can't exercise the compact
+/// binary-format branch of `TurkishId`'s serde impls; this does.
+#[cfg(feature = "serde")]
+mod binary_format {
+    use serde::{de, ser};
+    use std::fmt;
+
+    #[derive(Debug)]
+    pub struct Error(String);
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl ser::Error for Error {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            Error(msg.to_string())
+        }
+    }
+
+    impl de::Error for Error {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            Error(msg.to_string())
+        }
+    }
+
+    fn unsupported<T>(what: &str) -> Result<T, Error> {
+        Err(Error(format!("unsupported: {what}")))
+    }
+
+    #[derive(Default)]
+    pub struct Serializer {
+        pub out: Option<u32>,
+    }
+
+    impl ser::Serializer for &mut Serializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = ser::Impossible<(), Error>;
+        type SerializeTuple = ser::Impossible<(), Error>;
+        type SerializeTupleStruct = ser::Impossible<(), Error>;
+        type SerializeTupleVariant = ser::Impossible<(), Error>;
+        type SerializeMap = ser::Impossible<(), Error>;
+        type SerializeStruct = ser::Impossible<(), Error>;
+        type SerializeStructVariant = ser::Impossible<(), Error>;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.out = Some(v);
+            Ok(())
+        }
+
+        fn serialize_bool(self, _v: bool) -> Result<(), Error> {
+            unsupported("bool")
+        }
+        fn serialize_i8(self, _v: i8) -> Result<(), Error> {
+            unsupported("i8")
+        }
+        fn serialize_i16(self, _v: i16) -> Result<(), Error> {
+            unsupported("i16")
+        }
+        fn serialize_i32(self, _v: i32) -> Result<(), Error> {
+            unsupported("i32")
+        }
+        fn serialize_i64(self, _v: i64) -> Result<(), Error> {
+            unsupported("i64")
+        }
+        fn serialize_u8(self, _v: u8) -> Result<(), Error> {
+            unsupported("u8")
+        }
+        fn serialize_u16(self, _v: u16) -> Result<(), Error> {
+            unsupported("u16")
+        }
+        fn serialize_u64(self, _v: u64) -> Result<(), Error> {
+            unsupported("u64")
+        }
+        fn serialize_f32(self, _v: f32) -> Result<(), Error> {
+            unsupported("f32")
+        }
+        fn serialize_f64(self, _v: f64) -> Result<(), Error> {
+            unsupported("f64")
+        }
+        fn serialize_char(self, _v: char) -> Result<(), Error> {
+            unsupported("char")
+        }
+        fn serialize_str(self, _v: &str) -> Result<(), Error> {
+            unsupported("str")
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<(), Error> {
+            unsupported("bytes")
+        }
+        fn serialize_none(self) -> Result<(), Error> {
+            unsupported("none")
+        }
+        fn serialize_some<T: ?Sized + ser::Serialize>(self, _value: &T) -> Result<(), Error> {
+            unsupported("some")
+        }
+        fn serialize_unit(self) -> Result<(), Error> {
+            unsupported("unit")
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+            unsupported("unit_struct")
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _variant_index: u32,
+            _variant: &'static str,
+        ) -> Result<(), Error> {
+            unsupported("unit_variant")
+        }
+        fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+            self,
+            _name: &'static str,
+            _variant_index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<(), Error> {
+            unsupported("newtype_variant")
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            unsupported("seq")
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+            unsupported("tuple")
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            unsupported("tuple_struct")
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _variant_index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            unsupported("tuple_variant")
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            unsupported("map")
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            unsupported("struct")
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _variant_index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            unsupported("struct_variant")
+        }
+    }
+
+    pub struct Deserializer {
+        pub input: u32,
+    }
+
+    impl<'de> de::Deserializer<'de> for &mut Deserializer {
+        type Error = Error;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn deserialize_u32<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_u32(self.input)
+        }
+
+        fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+            unsupported("any")
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrips_through_non_human_readable_formats() {
+    use binary_format::{Deserializer, Serializer};
+    use serde::{Deserialize, Serialize};
+
+    for number in VALID_NUMBERS {
+        let id = number.parse::<TurkishId>().unwrap();
+
+        let mut serializer = Serializer::default();
+        id.serialize(&mut serializer).unwrap();
+        let seq = serializer.out.unwrap();
+        assert_eq!(seq, id.to_seq());
+
+        let mut deserializer = Deserializer { input: seq };
+        let decoded = TurkishId::deserialize(&mut deserializer).unwrap();
+        assert_eq!(id, decoded);
+    }
+}
+
+#[test]
+fn scan_bounded_finds_only_exact_tokens() {
+    let text = format!("a{} b{}9 c{}", VALID_NUMBERS[0], VALID_NUMBERS[1], VALID_NUMBERS[2]);
+    let found: Vec<_> = TurkishId::scan(&text, ScanMode::Bounded).collect();
+    // the middle number is embedded in a longer run and must be skipped
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].1.to_string(), VALID_NUMBERS[0]);
+    assert_eq!(found[1].1.to_string(), VALID_NUMBERS[2]);
+}
+
+#[test]
+fn scan_embedded_finds_matches_inside_longer_runs() {
+    let text = format!("{}9", VALID_NUMBERS[0]);
+    let found: Vec<_> = TurkishId::scan(&text, ScanMode::Embedded).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0, 0);
+    assert_eq!(found[0].1.to_string(), VALID_NUMBERS[0]);
+}
+
+#[test]
+fn scan_reports_correct_byte_offsets() {
+    let text = format!("prefix-{}-{}", VALID_NUMBERS[0], VALID_NUMBERS[1]);
+    let found: Vec<_> = TurkishId::scan(&text, ScanMode::Bounded).collect();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].0, text.find(VALID_NUMBERS[0]).unwrap());
+    assert_eq!(found[1].0, text.find(VALID_NUMBERS[1]).unwrap());
+}
+
+#[test]
+fn scan_ignores_invalid_checksums() {
+    let text = format!("{} {}", INVALID_NUMBERS[1].0, VALID_NUMBERS[0]);
+    let found: Vec<_> = TurkishId::scan(&text, ScanMode::Bounded).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].1.to_string(), VALID_NUMBERS[0]);
+}
+
+#[test]
+fn scan_offsets_agrees_with_scan() {
+    let text = format!("a{} b{}9 c{}", VALID_NUMBERS[0], VALID_NUMBERS[1], VALID_NUMBERS[2]);
+    for mode in [ScanMode::Bounded, ScanMode::Embedded] {
+        let offsets: Vec<_> = TurkishId::scan_offsets(&text, mode).collect();
+        let scanned: Vec<_> = TurkishId::scan(&text, mode).map(|(offset, _)| offset).collect();
+        assert_eq!(offsets, scanned);
+    }
+}
+
+#[test]
+fn scan_offsets_ignores_invalid_checksums() {
+    let text = format!("{} {}", INVALID_NUMBERS[1].0, VALID_NUMBERS[0]);
+    let found: Vec<_> = TurkishId::scan_offsets(&text, ScanMode::Bounded).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0], text.find(VALID_NUMBERS[0]).unwrap());
+}
+
+#[test]
+fn scan_empty_text_yields_nothing() {
+    assert_eq!(TurkishId::scan("", ScanMode::Bounded).count(), 0);
+    assert_eq!(TurkishId::scan("no digits here", ScanMode::Embedded).count(), 0);
+}
+
+#[test]
+fn scan_embedded_runs_in_linear_time() {
+    // A long run of digits with no match inside it used to force
+    // ScanMode::Embedded to rescan the run's remainder on every sliding
+    // step, making `scan` quadratic in the run length. Pick a run long
+    // enough that a quadratic scan would time the test out, but cheap
+    // enough that a linear one finishes instantly.
+    let text = "1".repeat(200_000);
+    assert_eq!(TurkishId::scan(&text, ScanMode::Embedded).count(), 0);
+}
+
+const VALID_TAX_IDS: &[&str] = &[
+    "1234567890",
+    "1111111122",
+    "9999999994",
+    "1000000000",
+    "8501234563",
+    "7293847561",
+    "1357924685",
+    "2468135797",
+];
+
+const INVALID_TAX_IDS: &[(&str, TaxIdError)] = &[
+    ("1234567891", TaxIdError::InvalidChecksum),
+    ("1111111123", TaxIdError::InvalidChecksum),
+    ("A234567890", TaxIdError::InvalidCharacter('A')),
+    ("123456789", TaxIdError::InvalidLength),
+    ("12345678901", TaxIdError::InvalidLength),
+    ("", TaxIdError::InvalidLength),
+];
+
+#[test]
+fn is_valid_tax_id_validnumbers_returns_true() {
+    for number in VALID_TAX_IDS {
+        assert!(is_valid_tax_id(number));
+    }
+}
+
+#[test]
+fn is_valid_tax_id_invalidnumbers_returns_false() {
+    for (number, _) in INVALID_TAX_IDS {
+        assert!(!is_valid_tax_id(number));
+    }
+}
+
+#[test]
+fn parse_tax_id_invalidnumbers_returns_correct_error() {
+    for (number, error) in INVALID_TAX_IDS {
+        assert_eq!(*error, number.parse::<TaxId>().err().unwrap());
+    }
+}
+
+#[test]
+fn tax_id_hashset_compatible() {
+    let mut set = HashSet::new();
+    let num = VALID_TAX_IDS[0].parse::<TaxId>().unwrap();
+    set.insert(num);
+    let num2 = VALID_TAX_IDS[0].parse::<TaxId>().unwrap();
+    set.insert(num2);
+    assert_eq!(num2, num);
+    assert_eq!(1, set.len());
+}
+
+#[test]
+fn tax_id_display_returnsthesamerepresentation() {
+    for number in VALID_TAX_IDS {
+        let id = TaxId::from_str(number).unwrap();
+        let idstr = format!("{id}");
+        assert_eq!(idstr, *number);
+    }
+}